@@ -19,6 +19,7 @@ import {
     serializeQueryParams,
 } from "@/tools/options";
 import { isFormData, convertToFormDataIfNeeded } from "@/tools/formdata";
+import { formatDate } from "@/tools/dates";
 
 export interface BeforeSendResult {
     [key: string]: any; // for backward compatibility
@@ -26,6 +27,86 @@ export interface BeforeSendResult {
     options?: { [key: string]: any };
 }
 
+// the max server specified Retry-After delay (in ms) the client is willing to wait
+// (the request is not retried if the server asks for a longer delay)
+const maxRetryAfter = 30000;
+
+// request methods without side effects that are safe to retry on any transient failure
+const safeRetryMethods = ["GET", "HEAD", "OPTIONS"];
+
+// known (lowercased) fetch TypeError messages when the server couldn't be reached
+const networkErrorMessages = [
+    "fetch failed", // Node
+    "failed to fetch", // Chromium
+    "networkerror", // Firefox
+    "load failed", // Safari
+    "network connection was lost", // Safari
+    "internet connection appears to be offline", // Safari
+    "network request failed", // React Native
+    "error sending request", // Deno
+];
+
+// checks whether the fetch rejection is a connection failure
+// (all other TypeErrors, eg. invalid url or header, are request construction errors).
+function isFetchNetworkError(err: any): boolean {
+    if (!(err instanceof TypeError)) {
+        return false;
+    }
+
+    const message = (err.message || "").toLowerCase();
+
+    return networkErrorMessages.some((m) => message.includes(m));
+}
+
+// checks whether the failed request could be automatically retried.
+//
+// Requests with side effects (POST, PATCH, DELETE, etc.) are retried only when
+// the server didn't process them (connection failures, 429 and 503 responses).
+function canRetry(method: string | undefined, err: any): boolean {
+    if (!err?.isRetryable || (err.retryAfter || 0) > maxRetryAfter) {
+        return false;
+    }
+
+    if (safeRetryMethods.includes((method || "GET").toUpperCase())) {
+        return true;
+    }
+
+    return err.isNetworkError || err.status == 429 || err.status == 503;
+}
+
+// returns the delay (in ms) before the next retry attempt
+// (exponential backoff with jitter, unless the server specified one).
+function retryDelay(attempt: number, retryAfter?: number | null): number {
+    if (typeof retryAfter === "number") {
+        return retryAfter;
+    }
+
+    const delay = Math.min(200 * Math.pow(2, attempt), 5000);
+
+    return delay / 2 + Math.random() * (delay / 2);
+}
+
+// waits for the specified ms or until the signal is aborted.
+function sleep(ms: number, signal?: AbortSignal | null): Promise<void> {
+    return new Promise((resolve) => {
+        if (signal?.aborted) {
+            return resolve();
+        }
+
+        const onAbort = () => {
+            clearTimeout(timeoutId);
+            resolve();
+        };
+
+        const timeoutId = setTimeout(() => {
+            signal?.removeEventListener("abort", onAbort);
+            resolve();
+        }, ms);
+
+        signal?.addEventListener("abort", onAbort, { once: true });
+    });
+}
+
 /**
  * PocketBase JS Client.
  */
@@ -158,6 +239,23 @@ export default class Client {
      */
     readonly crons: CronService;
 
+    /**
+     * The max number of times a failed request will be retried in case
+     * of a transient failure (network error, 429, 503, etc.; see `ClientResponseError.isRetryable`).
+     *
+     * The retries are delayed with exponential backoff or
+     * with the server `Retry-After` header value (if any; delays
+     * longer than 30s are not waited and the error is thrown instead).
+     *
+     * Requests with side effects (POST, PATCH, DELETE, etc.) are
+     * retried only on connection failures, 429 and 503 responses
+     * to avoid processing them twice.
+     *
+     * Default to `0` (aka. no retries). It could be also changed
+     * per request with the `maxRetries` send option.
+     */
+    maxRetries: number = 0;
+
     private cancelControllers: { [key: string]: AbortController } = {};
     private recordServices: { [key: string]: RecordService } = {};
     private enableAutoCancellation: boolean = true;
@@ -226,6 +324,13 @@ export default class Client {
         return this.recordServices[idOrName];
     }
 
+    /**
+     * Checks whether the auto cancellation for pending duplicated requests is enabled.
+     */
+    get isAutoCancellationEnabled(): boolean {
+        return this.enableAutoCancellation;
+    }
+
     /**
      * Globally enable or disable auto cancellation for pending duplicated requests.
      */
@@ -302,7 +407,7 @@ export default class Client {
                     if (val === null) {
                         val = "null";
                     } else if (val instanceof Date) {
-                        val = "'" + val.toISOString().replace("T", " ") + "'";
+                        val = "'" + formatDate(val) + "'";
                     } else {
                         val = "'" + JSON.stringify(val).replace(/'/g, "\\'") + "'";
                     }
@@ -373,6 +478,33 @@ export default class Client {
      * @throws {ClientResponseError}
      */
     async send<T = any>(path: string, options: SendOptions): Promise<T> {
+        return this.sendRequest<T>(path, options, false);
+    }
+
+    /**
+     * Sends an api http request and resolves with the raw fetch `Response`
+     * without reading its body (eg. for file downloads).
+     *
+     * The request goes through the same send hooks, auto cancellation, retries and
+     * timeout handling as `send()`, with the exception that for successful responses
+     * `afterSend` is called with an empty data object and its result is ignored.
+     *
+     * Note that the `timeout` option covers only the time until
+     * the response headers arrive.
+     *
+     * @throws {ClientResponseError}
+     */
+    async sendRaw(path: string, options: SendOptions): Promise<Response> {
+        return this.sendRequest<Response>(path, options, true);
+    }
+
+    // sends the api http request and resolves either with the parsed
+    // response data or with the raw fetch Response (if raw is true).
+    private async sendRequest<T>(
+        path: string,
+        options: SendOptions,
+        raw: boolean,
+    ): Promise<T> {
         options = this.initSendOptions(path, options);
 
         // build url + path
@@ -417,44 +549,130 @@ export default class Client {
         // early throw an abort error in case the request was already cancelled
         const fetchFunc = options.fetch || fetch;
 
-        // send the request
-        return fetchFunc(url, options)
-            .then(async (response) => {
-                let data: any = {};
+        const maxRetries = options.maxRetries ?? this.maxRetries;
+        delete options.maxRetries;
+
+        // abort the request if it doesn't complete within the specified timeout
+        // (the timeout controller is chained to the auto cancellation signal, if any)
+        const timeout = options.timeout || 0;
+        delete options.timeout;
+
+        let timeoutId: any;
+        let timedOut = false;
+        if (timeout > 0) {
+            const controller = new AbortController();
+            const parentSignal = options.signal;
+            if (parentSignal?.aborted) {
+                controller.abort();
+            } else {
+                parentSignal?.addEventListener("abort", () => controller.abort());
+            }
+            options.signal = controller.signal;
+            timeoutId = setTimeout(() => {
+                timedOut = true;
+                controller.abort();
+            }, timeout);
+        }
+
+        const request = (): Promise<T> =>
+            fetchFunc(url, options)
+                .catch((err) => {
+                    if (isFetchNetworkError(err)) {
+                        throw new ClientResponseError({
+                            originalError: err,
+                            isNetworkError: true,
+                        });
+                    }
+
+                    throw err;
+                })
+                .then(async (response) => {
+                    // skip reading the body of the successful raw responses
+                    if (raw && response.status < 400) {
+                        if (this.afterSend) {
+                            await this.afterSend(response, {}, options);
+                        }
 
+                        return response as any as T;
+                    }
+
+                    let data: any = {};
+
+                    try {
+                        data = await response.json();
+                    } catch (err) {
+                        if (
+                            options.signal?.aborted ||
+                            // note: don't check for the exception name due to platform discrepancies
+                            (typeof DOMException !== "undefined" &&
+                                err instanceof DOMException)
+                        ) {
+                            throw err;
+                        }
+
+                        // all api responses are expected to return json
+                        // with exception of the realtime events and 204
+                    }
+
+                    if (this.afterSend) {
+                        data = await this.afterSend(response, data, options);
+                    }
+
+                    if (response.status >= 400) {
+                        throw new ClientResponseError({
+                            url: response.url,
+                            status: response.status,
+                            headers: response.headers,
+                            data: data,
+                        });
+                    }
+
+                    return data as T;
+                })
+                .catch((err) => {
+                    // wrap to normalize all errors
+                    throw new ClientResponseError(err);
+                });
+
+        // send the request (and retry on transient failures, if enabled)
+        try {
+            for (let attempt = 0; ; attempt++) {
                 try {
-                    data = await response.json();
-                } catch (err) {
+                    return await request();
+                } catch (err: any) {
                     if (
+                        attempt >= maxRetries ||
                         options.signal?.aborted ||
-                        // note: don't check for the exception name due to platform discrepancies
-                        (typeof DOMException !== "undefined" && err instanceof DOMException)
+                        !canRetry(options.method, err)
                     ) {
                         throw err;
                     }
 
-                    // all api responses are expected to return json
-                    // with exception of the realtime events and 204
-                }
-
-                if (this.afterSend) {
-                    data = await this.afterSend(response, data, options);
-                }
+                    await sleep(retryDelay(attempt, err.retryAfter), options.signal);
 
-                if (response.status >= 400) {
-                    throw new ClientResponseError({
-                        url: response.url,
-                        status: response.status,
-                        data: data,
-                    });
+                    // cancelled while waiting for the next attempt
+                    if (options.signal?.aborted) {
+                        throw new ClientResponseError({
+                            url: err.url,
+                            isAbort: true,
+                            originalError: err,
+                        });
+                    }
                 }
+            }
+        } catch (err) {
+            // normalize the timeout error to distinguish it from a regular cancellation
+            if (timedOut) {
+                const timeoutErr = new ClientResponseError(err);
+                timeoutErr.isAbort = true;
+                timeoutErr.message = "The request timed out after " + timeout + "ms.";
+                throw timeoutErr;
+            }
 
-                return data as T;
-            })
-            .catch((err) => {
-                // wrap to normalize all errors
-                throw new ClientResponseError(err);
-            });
+            throw err;
+        } finally {
+            clearTimeout(timeoutId);
+        }
     }
 
     /**
@@ -1,3 +1,9 @@
+export interface FieldError {
+    code: string;
+    message: string;
+    [key: string]: any;
+}
+
 /**
  * ClientResponseError is a custom Error class that is intended to wrap
  * and normalize any error thrown by `Client.send()`.
@@ -9,6 +15,21 @@ export class ClientResponseError extends Error {
     isAbort: boolean = false;
     originalError: any = null;
 
+    /**
+     * Indicates that the request failed to reach the server
+     * (eg. connection refused, DNS lookup failure, etc.).
+     */
+    isNetworkError: boolean = false;
+
+    /**
+     * The amount of milliseconds the server asked the client to wait
+     * before retrying the request (parsed from the `Retry-After` or
+     * `RateLimit-Reset` response headers).
+     *
+     * It is `null` if the response doesn't have such headers.
+     */
+    retryAfter: number | null = null;
+
     constructor(errData?: any) {
         super("ClientResponseError");
 
@@ -20,8 +41,15 @@ export class ClientResponseError extends Error {
             this.url = typeof errData.url === "string" ? errData.url : "";
             this.status = typeof errData.status === "number" ? errData.status : 0;
             this.isAbort = !!errData.isAbort;
+            this.isNetworkError = !!errData.isNetworkError;
             this.originalError = errData.originalError;
 
+            if (typeof errData.retryAfter === "number") {
+                this.retryAfter = errData.retryAfter;
+            } else if (errData.headers) {
+                this.retryAfter = parseRetryAfter(errData.headers);
+            }
+
             if (errData.response !== null && typeof errData.response === "object") {
                 this.response = errData.response;
             } else if (errData.data !== null && typeof errData.data === "object") {
@@ -66,6 +94,86 @@ export class ClientResponseError extends Error {
         return this.response;
     }
 
+    /**
+     * Returns the validation errors of the individual body fields
+     * (eg. `{ "title": { "code": "validation_required", "message": "..." } }`).
+     *
+     * Nested and non-field error data entries are skipped.
+     */
+    get fieldErrors(): { [key: string]: FieldError } {
+        const result: { [key: string]: FieldError } = {};
+
+        const data = this.response?.data;
+        if (data === null || typeof data !== "object") {
+            return result;
+        }
+
+        for (let key in data) {
+            if (
+                typeof data[key]?.code === "string" &&
+                typeof data[key]?.message === "string"
+            ) {
+                result[key] = data[key];
+            }
+        }
+
+        return result;
+    }
+
+    /**
+     * Checks whether the error is a 400 response with at least one field validation error.
+     */
+    get isValidationError(): boolean {
+        return this.status === 400 && Object.keys(this.fieldErrors).length > 0;
+    }
+
+    /**
+     * Checks whether the error is a 401 Unauthorized or 403 Forbidden response.
+     */
+    get isAuthError(): boolean {
+        return this.status === 401 || this.status === 403;
+    }
+
+    /**
+     * Checks whether the error is a 429 Too Many Requests response.
+     */
+    get isRateLimited(): boolean {
+        return this.status === 429;
+    }
+
+    /**
+     * Returns the MFA id from the 401 auth response when the auth
+     * collection requires a second authentication method.
+     *
+     * It is an empty string if the error is not a MFA challenge.
+     * The returned id could be passed as `mfaId` option to the next
+     * auth call (eg. `authWithOTP(otpId, password, { mfaId })`).
+     */
+    get mfaId(): string {
+        const mfaId = this.response?.mfaId;
+
+        return typeof mfaId === "string" ? mfaId : "";
+    }
+
+    /**
+     * Loosely checks whether the failed request could be safely retried
+     * (eg. network errors, 429 Too Many Requests, 503 Service Unavailable, etc.).
+     *
+     * Aborted/cancelled requests are never considered retryable.
+     */
+    get isRetryable(): boolean {
+        if (this.isAbort) {
+            return false;
+        }
+
+        // the server couldn't be reached
+        if (this.isNetworkError) {
+            return true;
+        }
+
+        return retryableStatuses.includes(this.status);
+    }
+
     /**
      * Make a POJO's copy of the current error class instance.
      * @see https://github.com/vuex-orm/vuex-orm/issues/255
@@ -74,3 +182,44 @@ export class ClientResponseError extends Error {
         return { ...this };
     }
 }
+
+const retryableStatuses = [408, 425, 429, 500, 502, 503, 504];
+
+// extracts the retry delay (in ms) from the provided response headers.
+function parseRetryAfter(headers: any): number | null {
+    const raw =
+        getHeaderValue(headers, "Retry-After") ||
+        getHeaderValue(headers, "RateLimit-Reset");
+    if (!raw) {
+        return null;
+    }
+
+    // delay in seconds
+    if (/^\d+$/.test(raw)) {
+        return +raw * 1000;
+    }
+
+    // HTTP date
+    const date = Date.parse(raw);
+    if (!isNaN(date)) {
+        return Math.max(0, date - Date.now());
+    }
+
+    return null;
+}
+
+// returns the header value from either a Headers instance or a plain object.
+function getHeaderValue(headers: any, name: string): string {
+    if (typeof headers?.get === "function") {
+        return (headers.get(name) || "").trim();
+    }
+
+    name = name.toLowerCase();
+    for (let key in headers) {
+        if (key.toLowerCase() == name) {
+            return ("" + headers[key]).trim();
+        }
+    }
+
+    return "";
+}
@@ -16,5 +16,6 @@ export * from "@/tools/dtos";
 export * from "@/tools/options";
 export * from "@/tools/cookie";
 export * from "@/tools/jwt";
+export * from "@/tools/dates";
 
 export default Client;
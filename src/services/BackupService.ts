@@ -1,5 +1,5 @@
 import { BaseService } from "@/services/BaseService";
-import { CommonOptions } from "@/tools/options";
+import { CommonOptions, SendOptions } from "@/tools/options";
 
 export interface BackupFileInfo {
     key: string;
@@ -128,4 +128,33 @@ export class BackupService extends BaseService {
             `/api/backups/${encodeURIComponent(key)}?token=${encodeURIComponent(token)}`,
         );
     }
+
+    /**
+     * Downloads a single existing backup and resolves with the raw fetch `Response`
+     * so that the archive could be streamed without buffering it in memory.
+     *
+     * The superuser file token required for the download is
+     * automatically requested via `pb.files.getToken()`.
+     *
+     * ```js
+     * const response = await pb.backups.download("pb_backup_20240101.zip");
+     *
+     * // eg. in Node.js
+     * await pipeline(Readable.fromWeb(response.body), fs.createWriteStream("backup.zip"));
+     * ```
+     *
+     * @throws {ClientResponseError}
+     */
+    async download(key: string, options?: SendOptions): Promise<Response> {
+        options = Object.assign({}, options);
+
+        const token = await this.client.files.getToken({
+            headers: options.headers,
+            requestKey: null,
+        });
+
+        options.query = Object.assign({}, options.query, { token });
+
+        return this.client.sendRaw(`/api/backups/${encodeURIComponent(key)}`, options);
+    }
 }
@@ -1,7 +1,12 @@
 import { BaseService } from "@/services/BaseService";
 import { ClientResponseError } from "@/ClientResponseError";
 import { ListResult } from "@/tools/dtos";
-import { CommonOptions, ListOptions, FullListOptions } from "@/tools/options";
+import {
+    CommonOptions,
+    ListOptions,
+    FullListOptions,
+    IterateOptions,
+} from "@/tools/options";
 
 export abstract class CrudService<M> extends BaseService {
     /**
@@ -36,7 +41,12 @@ export abstract class CrudService<M> extends BaseService {
         options?: ListOptions,
     ): Promise<Array<T>> {
         if (typeof batchOrqueryParams == "number") {
-            return this._getFullList<T>(batchOrqueryParams, options);
+            options = Object.assign({}, options);
+
+            const checkConsistency = !!options.checkConsistency;
+            delete options.checkConsistency;
+
+            return this._getFullList<T>(batchOrqueryParams, options, checkConsistency);
         }
 
         options = Object.assign({}, batchOrqueryParams, options);
@@ -47,7 +57,57 @@ export abstract class CrudService<M> extends BaseService {
             delete options.batch;
         }
 
-        return this._getFullList<T>(batch, options);
+        const checkConsistency = !!options.checkConsistency;
+        delete options.checkConsistency;
+
+        return this._getFullList<T>(batch, options, checkConsistency);
+    }
+
+    /**
+     * Returns an async iterator that lazily fetches the list items page by page
+     * (by default 500 items per request; to change it set the `batch` query param).
+     *
+     * Unlike `getFullList`, only a single page is kept in memory at a time
+     * (the `checkConsistency` option is not supported).
+     *
+     * You can use the generic T to supply a wrapper type of the crud model.
+     *
+     * ```js
+     * for await (const item of pb.collection("example").iterate({ sort: "created" })) {
+     *     console.log(item.id);
+     * }
+     * ```
+     *
+     * @throws {ClientResponseError}
+     */
+    async *iterate<T = M>(options?: IterateOptions): AsyncGenerator<T, void, undefined> {
+        options = Object.assign({}, options);
+
+        let batch = 500;
+        if (options.batch) {
+            batch = options.batch;
+            delete options.batch;
+        }
+
+        // not supported (removed in case of plain js calls to avoid sending it as query param)
+        delete options.checkConsistency;
+
+        options.query = Object.assign(
+            {
+                skipTotal: 1,
+            },
+            options.query,
+        );
+
+        for (let page = 1; ; page++) {
+            const list = await this.getList<T>(page, batch, options);
+
+            yield* list.items;
+
+            if (list.items.length != list.perPage) {
+                return;
+            }
+        }
     }
 
     /**
@@ -239,6 +299,7 @@ export abstract class CrudService<M> extends BaseService {
     protected _getFullList<T = M>(
         batchSize = 500,
         options?: ListOptions,
+        checkConsistency = false,
     ): Promise<Array<T>> {
         options = options || {};
         options.query = Object.assign(
@@ -248,19 +309,62 @@ export abstract class CrudService<M> extends BaseService {
             options.query,
         );
 
+        // the totals are required for the consistency check
+        // (the top-level option is also removed because it has precedence over the query)
+        if (checkConsistency) {
+            delete options.skipTotal;
+            options.query.skipTotal = 0;
+        }
+
+        const maxRestarts = 3;
+
+        let restarts = 0;
+        let totalItems = 0;
         let result: Array<T> = [];
 
+        let restart = async (): Promise<Array<any>> => {
+            if (restarts >= maxRestarts) {
+                throw new ClientResponseError({
+                    url: this.client.buildURL(this.baseCrudPath),
+                    status: 409,
+                    response: {
+                        code: 409,
+                        message:
+                            "The list items changed while fetching them. Please try again.",
+                        data: {},
+                    },
+                });
+            }
+
+            restarts++;
+            result = [];
+
+            return request(1);
+        };
+
         let request = async (page: number): Promise<Array<any>> => {
             return this.getList(page, batchSize || 500, options).then((list) => {
                 const castedList = list as any as ListResult<T>;
                 const items = castedList.items;
 
+                if (checkConsistency) {
+                    if (page == 1) {
+                        totalItems = list.totalItems;
+                    } else if (totalItems != list.totalItems) {
+                        return restart();
+                    }
+                }
+
                 result = result.concat(items);
 
                 if (items.length == list.perPage) {
                     return request(page + 1);
                 }
 
+                if (checkConsistency && result.length != totalItems) {
+                    return restart();
+                }
+
                 return result;
             });
         };
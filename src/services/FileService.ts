@@ -1,5 +1,10 @@
+import { ClientResponseError } from "@/ClientResponseError";
 import { BaseService } from "@/services/BaseService";
-import { CommonOptions, FileOptions } from "@/tools/options";
+import {
+    CommonOptions,
+    FileOptions,
+    normalizeUnknownQueryParams,
+} from "@/tools/options";
 
 export class FileService extends BaseService {
     /**
@@ -22,22 +27,12 @@ export class FileService extends BaseService {
         filename: string,
         queryParams: FileOptions = {},
     ): string {
-        if (
-            !filename ||
-            !record?.id ||
-            !(record?.collectionId || record?.collectionName)
-        ) {
+        const path = this.filePath(record, filename);
+        if (!path) {
             return "";
         }
 
-        const parts = [];
-        parts.push("api");
-        parts.push("files");
-        parts.push(encodeURIComponent(record.collectionId || record.collectionName));
-        parts.push(encodeURIComponent(record.id));
-        parts.push(encodeURIComponent(filename));
-
-        let result = this.client.buildURL(parts.join("/"));
+        let result = this.client.buildURL(path);
 
         if (Object.keys(queryParams).length) {
             // normalize the download query param for consistency with the Dart sdk
@@ -53,6 +48,54 @@ export class FileService extends BaseService {
         return result;
     }
 
+    /**
+     * Downloads the specified record file and resolves with its content as Blob.
+     *
+     * For protected files you can provide a file token as query parameter:
+     *
+     * ```js
+     * const token = await pb.files.getToken();
+     *
+     * const blob = await pb.files.download(record, record.document, { token });
+     * ```
+     *
+     * @throws {ClientResponseError}
+     */
+    async download(
+        record: { [key: string]: any },
+        filename: string,
+        options?: FileOptions,
+    ): Promise<Blob> {
+        const path = this.filePath(record, filename);
+        if (!path) {
+            throw new ClientResponseError({
+                status: 404,
+                response: {
+                    code: 404,
+                    message: "Missing required record id, collection or filename.",
+                    data: {},
+                },
+            });
+        }
+
+        options = Object.assign({ method: "GET" }, options);
+
+        // move the thumb, token, etc. options as query parameters
+        normalizeUnknownQueryParams(options);
+
+        // normalize the download query param for consistency with getURL()
+        if (options.query?.download === false) {
+            delete options.query.download;
+        }
+
+        const response = await this.client.sendRaw(path, options);
+
+        return response.blob().catch((err) => {
+            // wrap to normalize all errors
+            throw new ClientResponseError(err);
+        });
+    }
+
     /**
      * Requests a new private file access token for the current auth model.
      *
@@ -70,4 +113,25 @@ export class FileService extends BaseService {
             .send("/api/files/token", options)
             .then((data) => data?.token || "");
     }
+
+    // returns the relative record file path or empty string if the
+    // record id, collection or filename are missing.
+    private filePath(record: { [key: string]: any }, filename: string): string {
+        if (
+            !filename ||
+            !record?.id ||
+            !(record?.collectionId || record?.collectionName)
+        ) {
+            return "";
+        }
+
+        const parts = [];
+        parts.push("api");
+        parts.push("files");
+        parts.push(encodeURIComponent(record.collectionId || record.collectionName));
+        parts.push(encodeURIComponent(record.id));
+        parts.push(encodeURIComponent(filename));
+
+        return parts.join("/");
+    }
 }
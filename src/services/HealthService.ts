@@ -1,10 +1,20 @@
 import { BaseService } from "@/services/BaseService";
 import { CommonOptions } from "@/tools/options";
 
+export interface HealthCheckData {
+    [key: string]: any;
+
+    // the fields below are available only for superusers
+    canBackup?: boolean;
+    realIP?: string;
+    requireS3?: boolean;
+    possibleProxyHeader?: string;
+}
+
 export interface HealthCheckResponse {
     code: number;
     message: string;
-    data: { [key: string]: any };
+    data: HealthCheckData;
 }
 
 export class HealthService extends BaseService {
@@ -23,4 +33,26 @@ export class HealthService extends BaseService {
 
         return this.client.send("/api/health", options);
     }
+
+    /**
+     * Loosely checks whether the api is healthy
+     * (aka. the health check request was successful).
+     *
+     * Unlike `check()` this method doesn't throw and
+     * resolves with `false` on error.
+     *
+     * The request is not auto cancelled unless a custom `requestKey` is set
+     * (otherwise overlapping calls would resolve with `false`).
+     */
+    async isHealthy(options?: CommonOptions): Promise<boolean> {
+        options = Object.assign({}, options);
+
+        if (typeof options.requestKey === "undefined" && !options.$cancelKey) {
+            options.requestKey = null;
+        }
+
+        return this.check(options)
+            .then((result) => result?.code == 200)
+            .catch(() => false);
+    }
 }
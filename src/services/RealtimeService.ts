@@ -1,6 +1,7 @@
 import { ClientResponseError } from "@/ClientResponseError";
 import { BaseService } from "@/services/BaseService";
 import { SendOptions, normalizeUnknownQueryParams } from "@/tools/options";
+import { getTokenPayload } from "@/tools/jwt";
 
 interface promiseCallbacks {
     resolve: Function;
@@ -17,16 +18,26 @@ export class RealtimeService extends BaseService {
     private eventSource: EventSource | null = null;
     private subscriptions: Subscriptions = {};
     private lastSentSubscriptions: Array<string> = [];
+    private lastSentAuthId: string = "";
+    private unsubscribeAuthStoreChange?: () => void;
     private connectTimeoutId: any;
     private maxConnectTimeout: number = 15000;
     private reconnectTimeoutId: any;
     private reconnectAttempts: number = 0;
-    private maxReconnectAttempts: number = Infinity;
     private predefinedReconnectIntervals: Array<number> = [
         200, 300, 500, 1000, 1200, 1500, 2000,
     ];
     private pendingConnects: Array<promiseCallbacks> = [];
 
+    /**
+     * The max number of reconnect attempts after an interrupted
+     * realtime connection before giving up (default to `Infinity`).
+     *
+     * Once the limit is reached the realtime client is disconnected.
+     * Set it to `0` to disable the reconnects.
+     */
+    maxReconnectAttempts: number = Infinity;
+
     /**
      * Returns whether the realtime connection has been established.
      */
@@ -43,6 +54,9 @@ export class RealtimeService extends BaseService {
      * is a result from unsubscribing (`activeSubscriptions.length == 0`)
      * or because of network/server error (`activeSubscriptions.length > 0`).
      *
+     * The hook is not invoked when the connection is reestablished because of
+     * an auth store change (eg. on logout or when switching the auth record).
+     *
      * If you want to listen for the opposite, aka. when the client connection is established,
      * subscribe to the `PB_CONNECT` event.
      */
@@ -278,6 +292,7 @@ export class RealtimeService extends BaseService {
         this.addAllSubscriptionListeners();
 
         this.lastSentSubscriptions = this.getNonEmptySubscriptionKeys();
+        this.lastSentAuthId = this.getAuthId();
 
         return this.client
             .send("/api/realtime", {
@@ -372,8 +387,10 @@ export class RealtimeService extends BaseService {
         });
     }
 
-    private initConnect() {
-        this.disconnect(true);
+    private initConnect(skipDisconnectHook = false) {
+        this.disconnect(true, skipDisconnectHook);
+
+        this.bindAuthStoreChange();
 
         // wait up to 15s for connect
         clearTimeout(this.connectTimeoutId);
@@ -432,6 +449,59 @@ export class RealtimeService extends BaseService {
         });
     }
 
+    /**
+     * Registers an auth store change listener that takes care to
+     * update the realtime connection authorization state.
+     */
+    private bindAuthStoreChange() {
+        this.unsubscribeAuthStoreChange?.();
+
+        this.unsubscribeAuthStoreChange = this.client.authStore.onChange(() => {
+            if (!this.isConnected) {
+                return; // the latest auth state will be submitted on connect
+            }
+
+            const newAuthId = this.getAuthId();
+            if (newAuthId == this.lastSentAuthId) {
+                return; // same auth record (eg. on token refresh)
+            }
+
+            if (!this.lastSentAuthId) {
+                // guest -> authenticated
+                // (on failure reconnect to avoid keeping the guest authorization)
+                this.submitSubscriptions().catch(() => this.reconnect());
+            } else {
+                // the server doesn't allow changing the authorization of an already
+                // authenticated realtime client so we have to establish a new connection
+                this.reconnect(true);
+            }
+        });
+    }
+
+    /**
+     * Establishes a new realtime connection.
+     *
+     * It is handled as a regular reconnect so that in case of
+     * failure it will continue retrying in the background.
+     */
+    private reconnect(skipDisconnectHook = false) {
+        this.reconnectAttempts++;
+        this.initConnect(skipDisconnectHook);
+    }
+
+    /**
+     * Returns an identifier of the current auth record
+     * (the collection and record id) or empty string for guests.
+     */
+    private getAuthId(): string {
+        const payload = getTokenPayload(this.client.authStore.token);
+        if (!payload.id) {
+            return "";
+        }
+
+        return (payload.collectionId || "") + "/" + payload.id;
+    }
+
     private hasUnsentSubscriptions(): boolean {
         const latestTopics = this.getNonEmptySubscriptionKeys();
         if (latestTopics.length != this.lastSentSubscriptions.length) {
@@ -455,7 +525,7 @@ export class RealtimeService extends BaseService {
             // wasn't previously connected -> direct reject
             (!this.clientId && !this.reconnectAttempts) ||
             // was previously connected but the max reconnection limit has been reached
-            this.reconnectAttempts > this.maxReconnectAttempts
+            this.reconnectAttempts >= this.maxReconnectAttempts
         ) {
             for (let p of this.pendingConnects) {
                 p.reject(new ClientResponseError(err));
@@ -478,8 +548,8 @@ export class RealtimeService extends BaseService {
         }, timeout);
     }
 
-    private disconnect(fromReconnect = false): void {
-        if (this.clientId && this.onDisconnect) {
+    private disconnect(fromReconnect = false, skipDisconnectHook = false): void {
+        if (this.clientId && this.onDisconnect && !skipDisconnectHook) {
             this.onDisconnect(Object.keys(this.subscriptions));
         }
 
@@ -490,10 +560,14 @@ export class RealtimeService extends BaseService {
         this.eventSource?.close();
         this.eventSource = null;
         this.clientId = "";
+        this.lastSentAuthId = "";
 
         if (!fromReconnect) {
             this.reconnectAttempts = 0;
 
+            this.unsubscribeAuthStoreChange?.();
+            this.unsubscribeAuthStoreChange = undefined;
+
             // resolve any remaining connect promises
             //
             // this is done to avoid unnecessary throwing errors in case
@@ -3,18 +3,23 @@ import { ClientResponseError } from "@/ClientResponseError";
 import { RealtimeService, UnsubscribeFunc } from "@/services/RealtimeService";
 import { BaseAuthStore } from "@/stores/BaseAuthStore";
 import { CrudService } from "@/services/CrudService";
-import { ListResult, RecordModel } from "@/tools/dtos";
+import { ListResult, RecordModel, OAuth2ProviderName } from "@/tools/dtos";
 import { normalizeLegacyOptionsArgs } from "@/tools/legacy";
 import {
     CommonOptions,
     RecordFullListOptions,
+    RecordIterateOptions,
     RecordListOptions,
     RecordOptions,
     SendOptions,
     RecordSubscribeOptions,
 } from "@/tools/options";
 import { getTokenPayload } from "@/tools/jwt";
-import { registerAutoRefresh, resetAutoRefresh } from "@/tools/refresh";
+import {
+    getUserBeforeSend,
+    registerAutoRefresh,
+    resetAutoRefresh,
+} from "@/tools/refresh";
 
 export interface RecordAuthResponse<T = RecordModel> {
     /**
@@ -37,7 +42,7 @@ export interface RecordAuthResponse<T = RecordModel> {
 }
 
 export interface AuthProviderInfo {
-    name: string;
+    name: OAuth2ProviderName;
     displayName: string;
     state: string;
     authURL: string;
@@ -65,8 +70,10 @@ export interface AuthMethodsList {
     };
 }
 
+export type RecordSubscriptionAction = "create" | "update" | "delete";
+
 export interface RecordSubscription<T = RecordModel> {
-    action: string; // eg. create, update, delete
+    action: RecordSubscriptionAction;
     record: T;
 }
 
@@ -74,7 +81,7 @@ export type OAuth2UrlCallback = (url: string) => void | Promise<void>;
 
 export interface OAuth2AuthConfig extends SendOptions {
     // the name of the OAuth2 provider (eg. "google")
-    provider: string;
+    provider: OAuth2ProviderName;
 
     // custom scopes to overwrite the default ones
     scopes?: Array<string>;
@@ -214,6 +221,15 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
         return super.getFullList<T>(params);
     }
 
+    /**
+     * @inheritdoc
+     */
+    async *iterate<T = M>(
+        options?: RecordIterateOptions,
+    ): AsyncGenerator<T, void, undefined> {
+        yield* super.iterate<T>(options);
+    }
+
     /**
      * @inheritdoc
      */
@@ -419,7 +435,7 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
      * @throws {ClientResponseError}
      */
     async authWithOAuth2Code<T = M>(
-        provider: string,
+        provider: OAuth2ProviderName,
         code: string,
         codeVerifier: string,
         redirectURL: string,
@@ -432,7 +448,7 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
      * Consider using authWithOAuth2Code(provider, code, codeVerifier, redirectURL, createdData, options?).
      */
     async authWithOAuth2Code<T = M>(
-        provider: string,
+        provider: OAuth2ProviderName,
         code: string,
         codeVerifier: string,
         redirectURL: string,
@@ -442,7 +458,7 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
     ): Promise<RecordAuthResponse<T>>;
 
     async authWithOAuth2Code<T = M>(
-        provider: string,
+        provider: OAuth2ProviderName,
         code: string,
         codeVerifier: string,
         redirectURL: string,
@@ -1045,7 +1061,7 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
      */
     async unlinkExternalAuth(
         recordId: string,
-        provider: string,
+        provider: OAuth2ProviderName,
         options?: CommonOptions,
     ): Promise<boolean> {
         const ea = await this.client.collection("_externalAuths").getFirstListItem(
@@ -1110,6 +1126,10 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
      * Impersonate authenticates with the specified recordId and
      * returns a new client with the received auth token in a memory store.
      *
+     * The new client inherits the current client `lang`, `beforeSend`,
+     * `afterSend`, `maxRetries` and auto cancellation settings
+     * (the superuser `autoRefreshThreshold` handling is not inherited).
+     *
      * If `duration` is 0 the generated auth token will fallback
      * to the default collection auth token duration.
      *
@@ -1135,12 +1155,18 @@ export class RecordService<M = RecordModel> extends CrudService<M> {
         }
 
         // create a new client loaded with the impersonated auth state
+        // and inheriting the current client send configuration
         // ---
         const client = new Client(
             this.client.baseURL,
             new BaseAuthStore(),
             this.client.lang,
         );
+        // (excluding the superuser auto refresh hook of the current auth state)
+        client.beforeSend = getUserBeforeSend(this.client);
+        client.afterSend = this.client.afterSend;
+        client.maxRetries = this.client.maxRetries;
+        client.autoCancellation(this.client.isAutoCancellationEnabled);
 
         const authData = await client.send(
             this.baseCollectionPath + "/impersonate/" + encodeURIComponent(recordId),
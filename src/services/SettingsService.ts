@@ -1,4 +1,5 @@
 import { BaseService } from "@/services/BaseService";
+import { EmailTemplateName, SettingsModel } from "@/tools/dtos";
 import { CommonOptions } from "@/tools/options";
 
 interface appleClientSecret {
@@ -11,7 +12,7 @@ export class SettingsService extends BaseService {
      *
      * @throws {ClientResponseError}
      */
-    async getAll(options?: CommonOptions): Promise<{ [key: string]: any }> {
+    async getAll(options?: CommonOptions): Promise<SettingsModel> {
         options = Object.assign(
             {
                 method: "GET",
@@ -25,12 +26,21 @@ export class SettingsService extends BaseService {
     /**
      * Bulk updates app settings.
      *
+     * Only the provided settings groups/fields are updated, eg.:
+     *
+     * ```js
+     * await pb.settings.update({
+     *     meta: { appName: "Example" },
+     *     batch: { enabled: true },
+     * });
+     * ```
+     *
      * @throws {ClientResponseError}
      */
     async update(
         bodyParams?: { [key: string]: any } | FormData,
         options?: CommonOptions,
-    ): Promise<{ [key: string]: any }> {
+    ): Promise<SettingsModel> {
         options = Object.assign(
             {
                 method: "PATCH",
@@ -73,13 +83,18 @@ export class SettingsService extends BaseService {
      * - verification
      * - password-reset
      * - email-change
+     * - otp
+     * - login-alert
+     *
+     * On failure (eg. invalid SMTP configuration) the server error
+     * message is available in the thrown `ClientResponseError`.
      *
      * @throws {ClientResponseError}
      */
     async testEmail(
         collectionIdOrName: string,
         toEmail: string,
-        emailTemplate: string,
+        emailTemplate: EmailTemplateName,
         options?: CommonOptions,
     ): Promise<boolean> {
         options = Object.assign(
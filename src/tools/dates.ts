@@ -0,0 +1,26 @@
+/**
+ * Formats the provided Date object into the PocketBase datetime string format
+ * (eg. "2024-01-02 03:04:05.678Z").
+ */
+export function formatDate(date: Date): string {
+    return date.toISOString().replace("T", " ");
+}
+
+/**
+ * Parses a PocketBase datetime string (eg. record `created`, log `created`,
+ * backup `modified`, etc.) into a Date object.
+ *
+ * Returns `null` for empty or invalid datetime strings.
+ */
+export function parseDate(value: string | null | undefined): Date | null {
+    if (!value || typeof value !== "string") {
+        return null;
+    }
+
+    const date = new Date(value.trim().replace(" ", "T"));
+    if (isNaN(date.getTime())) {
+        return null;
+    }
+
+    return date;
+}
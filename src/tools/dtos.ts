@@ -13,7 +13,12 @@ export interface BaseModel {
 }
 
 export interface LogModel extends BaseModel {
-    level: string;
+    /**
+     * The numeric log level (-4: DEBUG, 0: INFO, 4: WARN, 8: ERROR).
+     *
+     * Logs can be filtered by level, eg. `pb.logs.getList(1, 50, { filter: "level >= 4" })`.
+     */
+    level: number;
     message: string;
     created: string;
     updated: string;
@@ -69,10 +74,50 @@ export interface PasswordAuthConfig {
     identityFields: Array<string>;
 }
 
+/**
+ * The names of the OAuth2 providers shipped with PocketBase.
+ *
+ * Any other string is also accepted to allow custom registered providers.
+ */
+export type OAuth2ProviderName =
+    | "apple"
+    | "bitbucket"
+    | "box"
+    | "discord"
+    | "facebook"
+    | "gitea"
+    | "gitee"
+    | "github"
+    | "gitlab"
+    | "google"
+    | "instagram2"
+    | "kakao"
+    | "lark"
+    | "linear"
+    | "livechat"
+    | "mailcow"
+    | "microsoft"
+    | "monday"
+    | "notion"
+    | "oidc"
+    | "oidc2"
+    | "oidc3"
+    | "patreon"
+    | "planningcenter"
+    | "spotify"
+    | "strava"
+    | "trakt"
+    | "twitch"
+    | "twitter"
+    | "vk"
+    | "wakatime"
+    | "yandex"
+    | (string & {});
+
 export interface OAuth2Provider {
     pkce?: boolean;
     clientId: string;
-    name: string;
+    name: OAuth2ProviderName;
     clientSecret: string;
     authURL: string;
     tokenURL: string;
@@ -138,3 +183,99 @@ export type CollectionModel =
     | BaseCollectionModel
     | ViewCollectionModel
     | AuthCollectionModel;
+
+// -------------------------------------------------------------------
+// Settings types
+// -------------------------------------------------------------------
+
+/**
+ * The names of the auth collection email templates that could be used with `pb.settings.testEmail()`.
+ *
+ * Any other string is also accepted for backward compatibility.
+ */
+export type EmailTemplateName =
+    | "verification"
+    | "password-reset"
+    | "email-change"
+    | "otp"
+    | "login-alert"
+    | (string & {});
+
+export interface MetaConfig {
+    appName: string;
+    appURL: string;
+    senderName: string;
+    senderAddress: string;
+    hideControls: boolean;
+}
+
+export interface LogsConfig {
+    maxDays: number;
+    minLevel: number;
+    logIP: boolean;
+    logAuthId: boolean;
+}
+
+export interface SMTPConfig {
+    enabled: boolean;
+    port: number;
+    host: string;
+    username: string;
+    password?: string;
+    authMethod: string;
+    tls: boolean;
+    localName: string;
+}
+
+export interface S3Config {
+    enabled: boolean;
+    bucket: string;
+    region: string;
+    endpoint: string;
+    accessKey: string;
+    secret?: string;
+    forcePathStyle: boolean;
+}
+
+export interface BackupsConfig {
+    cron: string;
+    cronMaxKeep: number;
+    s3: S3Config;
+}
+
+export interface BatchConfig {
+    enabled: boolean;
+    maxRequests: number;
+    timeout: number;
+    maxBodySize: number;
+}
+
+export interface RateLimitRule {
+    label: string;
+    audience: "" | "@guest" | "@auth";
+    duration: number;
+    maxRequests: number;
+}
+
+export interface RateLimitsConfig {
+    enabled: boolean;
+    rules: Array<RateLimitRule>;
+}
+
+export interface TrustedProxyConfig {
+    headers: Array<string>;
+    useLeftmostIP: boolean;
+}
+
+export interface SettingsModel {
+    [key: string]: any;
+
+    meta: MetaConfig;
+    logs: LogsConfig;
+    smtp: SMTPConfig;
+    s3: S3Config;
+    backups: BackupsConfig;
+    batch: BatchConfig;
+    rateLimits: RateLimitsConfig;
+    trustedProxy: TrustedProxyConfig;
+}
@@ -1,3 +1,5 @@
+import { formatDate } from "@/tools/dates";
+
 export interface SendOptions extends RequestInit {
     // for backward compatibility and to minimize the verbosity,
     // any top-level field that doesn't exist in RequestInit or the
@@ -37,6 +39,18 @@ export interface SendOptions extends RequestInit {
      */
     requestKey?: string | null;
 
+    /**
+     * The max number of retries in case of a transient request failure
+     * (overwrites the default `Client.maxRetries`).
+     */
+    maxRetries?: number;
+
+    /**
+     * The max amount of milliseconds to wait for the request to complete
+     * (including the retries) before aborting it with a timeout error.
+     */
+    timeout?: number;
+
     /**
      * @deprecated use `requestKey:string` instead
      */
@@ -60,10 +74,28 @@ export interface ListOptions extends CommonOptions {
     skipTotal?: boolean;
 }
 
-export interface FullListOptions extends ListOptions {
+export interface IterateOptions extends ListOptions {
     batch?: number;
 }
 
+export interface FullListOptions extends IterateOptions {
+
+    /**
+     * If enabled, the `totalItems` count of each fetched page is compared
+     * against the first one to detect items created or deleted while fetching
+     * (when enabled the `skipTotal` option is ignored).
+     *
+     * On mismatch the list is refetched from the first page
+     * (up to 3 times before throwing a 409 error).
+     *
+     * Note that changes that don't affect the total count (eg. an insert paired
+     * with a delete or items moved between the pages because of sorting by a
+     * changing field like `updated`) are not detected, and for collections
+     * with constant writes the fetching will most likely fail with the 409 error.
+     */
+    checkConsistency?: boolean;
+}
+
 export interface RecordOptions extends CommonOptions {
     expand?: string;
 }
@@ -72,6 +104,8 @@ export interface RecordListOptions extends ListOptions, RecordOptions {}
 
 export interface RecordFullListOptions extends FullListOptions, RecordOptions {}
 
+export interface RecordIterateOptions extends IterateOptions, RecordOptions {}
+
 export interface RecordSubscribeOptions extends SendOptions {
     fields?: string;
     filter?: string;
@@ -109,6 +143,8 @@ export interface AuthOptions extends CommonOptions {
 // list of known SendOptions keys (everything else is treated as query param)
 const knownSendOptionsKeys = [
     "requestKey",
+    "maxRetries",
+    "timeout",
     "$cancelKey",
     "$autoCancel",
     "fetch",
@@ -174,7 +210,7 @@ function prepareQueryParamValue(value: any): null | string {
     }
 
     if (value instanceof Date) {
-        return encodeURIComponent(value.toISOString().replace("T", " "));
+        return encodeURIComponent(formatDate(value));
     }
 
     if (typeof value === "object") {
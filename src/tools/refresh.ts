@@ -6,6 +6,13 @@ export function resetAutoRefresh(client: Client) {
     (client as any)._resetAutoRefresh?.();
 }
 
+// returns the client beforeSend hook excluding the auto refresh wrapper (if registered).
+export function getUserBeforeSend(client: Client): Client["beforeSend"] {
+    const getter = (client as any)._getUserBeforeSend;
+
+    return getter ? getter() : client.beforeSend;
+}
+
 export function registerAutoRefresh(
     client: Client,
     threshold: number,
@@ -35,9 +42,17 @@ export function registerAutoRefresh(
         unsubStoreChange();
         client.beforeSend = oldBeforeSend;
         delete (client as any)._resetAutoRefresh;
+        delete (client as any)._getUserBeforeSend;
     };
 
-    client.beforeSend = async (url, sendOptions) => {
+    // returns the hook that was wrapped (in case the beforeSend wasn't replaced afterwards)
+    (client as any)._getUserBeforeSend = function () {
+        return client.beforeSend === autoRefreshBeforeSend
+            ? oldBeforeSend
+            : client.beforeSend;
+    };
+
+    const autoRefreshBeforeSend: Client["beforeSend"] = async (url, sendOptions) => {
         const oldToken = client.authStore.token;
 
         if (sendOptions.query?.autoRefresh) {
@@ -81,4 +96,6 @@ export function registerAutoRefresh(
 
         return oldBeforeSend ? oldBeforeSend(url, sendOptions) : { url, sendOptions };
     };
+
+    client.beforeSend = autoRefreshBeforeSend;
 }
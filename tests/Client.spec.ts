@@ -576,6 +576,397 @@ describe("Client", function () {
             const response = client.send("/abc", { method: "GET", signal: controller.signal, requestKey: null });
             await expect(response).rejects.toThrow("request was autocancelled");
         });
+
+        test("Should expose the Retry-After response header in the thrown error", async function () {
+            const client = new Client("test_base_url");
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/limited",
+                replyCode: 429,
+                replyBody: { message: "too many requests" },
+                replyHeaders: { "Retry-After": "3" },
+            });
+
+            try {
+                await client.send("/limited", { method: "GET" });
+                assert.fail("expected to throw");
+            } catch (err: any) {
+                assert.equal(err.status, 429);
+                assert.equal(err.retryAfter, 3000);
+                assert.isTrue(err.isRetryable);
+            }
+        });
+
+        test("Should mark only the fetch connection failures as network errors", async function () {
+            const testCases: Array<[string, boolean, any]> = [
+                // scenario, expected isNetworkError, send options
+                [
+                    "connection failure",
+                    true,
+                    { fetch: () => Promise.reject(new TypeError("fetch failed")) },
+                ],
+                [
+                    "browser connection failure",
+                    true,
+                    { fetch: () => Promise.reject(new TypeError("Failed to fetch")) },
+                ],
+                [
+                    "invalid header",
+                    false,
+                    {
+                        fetch: () =>
+                            Promise.reject(new TypeError("Invalid header value.")),
+                    },
+                ],
+                [
+                    "body with GET request",
+                    false,
+                    {
+                        fetch: () =>
+                            Promise.reject(
+                                new TypeError(
+                                    "Request with GET/HEAD method cannot have body.",
+                                ),
+                            ),
+                    },
+                ],
+                [
+                    "invalid url",
+                    false,
+                    {
+                        fetch: () =>
+                            Promise.reject(new TypeError("Failed to parse URL from test")),
+                    },
+                ],
+                [
+                    "custom fetch bug",
+                    false,
+                    { fetch: () => Promise.reject(new Error("test")) },
+                ],
+            ];
+
+            for (const [scenario, expected, options] of testCases) {
+                const client = new Client("test_base_url");
+
+                try {
+                    await client.send("/network", Object.assign({ method: "GET" }, options));
+                    assert.fail("expected to throw");
+                } catch (err: any) {
+                    assert.equal(err.status, 0, scenario);
+                    assert.equal(err.isNetworkError, expected, scenario);
+                    assert.equal(err.isRetryable, expected, scenario);
+                }
+            }
+        });
+
+        test("Should not mark afterSend errors as network errors", async function () {
+            const client = new Client("test_base_url");
+
+            client.afterSend = function () {
+                throw new TypeError("test");
+            };
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/after_type_error",
+                replyCode: 200,
+            });
+
+            try {
+                await client.send("/after_type_error", { method: "GET" });
+                assert.fail("expected to throw");
+            } catch (err: any) {
+                assert.isFalse(err.isNetworkError);
+                assert.isFalse(err.isRetryable);
+            }
+        });
+
+        test("Should retry transient failures up to maxRetries times", async function () {
+            const client = new Client("test_base_url");
+            client.maxRetries = 2;
+
+            let calls = 0;
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/retry",
+                replyCode: 503,
+                replyHeaders: { "Retry-After": "0" },
+                additionalMatcher: () => ++calls <= 2,
+            });
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/retry",
+                replyCode: 200,
+                replyBody: "ok",
+            });
+
+            const response = await client.send("/retry", { method: "GET" });
+
+            assert.equal(response, "ok");
+            assert.equal(calls, 3);
+        });
+
+        test("Should throw after all retries are exhausted", async function () {
+            const client = new Client("test_base_url");
+
+            let calls = 0;
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/retry",
+                replyCode: 503,
+                replyHeaders: { "Retry-After": "0" },
+                additionalMatcher: () => ++calls > 0,
+            });
+
+            const response = client.send("/retry", { method: "GET", maxRetries: 1 });
+
+            await expect(response).rejects.toThrow();
+            assert.equal(calls, 2);
+        });
+
+        test("Should not retry non-transient failures", async function () {
+            const client = new Client("test_base_url");
+            client.maxRetries = 3;
+
+            let calls = 0;
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/retry",
+                replyCode: 400,
+                additionalMatcher: () => ++calls > 0,
+            });
+
+            const response = client.send("/retry", { method: "GET" });
+
+            await expect(response).rejects.toThrow();
+            assert.equal(calls, 1);
+        });
+    });
+
+    describe("retries", function () {
+        test("Should retry requests with side effects only if they were not processed", async function () {
+            const testCases: Array<[string, number, number]> = [
+                // method, reply status, expected calls
+                ["GET", 500, 2],
+                ["GET", 502, 2],
+                ["GET", 503, 2],
+                ["POST", 500, 1],
+                ["POST", 504, 1],
+                ["POST", 429, 2],
+                ["POST", 503, 2],
+                ["PATCH", 502, 1],
+                ["PATCH", 503, 2],
+                ["DELETE", 500, 1],
+                ["DELETE", 429, 2],
+            ];
+
+            for (const [method, status, expectedCalls] of testCases) {
+                const client = new Client("test_base_url");
+                client.maxRetries = 1;
+
+                let calls = 0;
+                fetchMock.on({
+                    method: method,
+                    url: "test_base_url/retry_" + method + status,
+                    replyCode: status,
+                    replyHeaders: { "Retry-After": "0" },
+                    additionalMatcher: () => ++calls > 0,
+                });
+
+                const response = client.send("/retry_" + method + status, { method });
+
+                await expect(response).rejects.toThrow();
+                assert.equal(calls, expectedCalls, method + " " + status);
+            }
+        });
+
+        test("Should retry requests with side effects on connection failures", async function () {
+            const client = new Client("test_base_url");
+
+            let calls = 0;
+            const response = client.send("/retry_network", {
+                method: "POST",
+                maxRetries: 2,
+                fetch: () => {
+                    calls++;
+                    return Promise.reject(new TypeError("fetch failed"));
+                },
+            });
+
+            await expect(response).rejects.toThrow();
+            assert.equal(calls, 3);
+        });
+
+        test("Should not retry if the server asks for a too long delay", async function () {
+            const client = new Client("test_base_url");
+            client.maxRetries = 2;
+
+            let calls = 0;
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/retry_long",
+                replyCode: 429,
+                replyHeaders: { "Retry-After": "3600" },
+                additionalMatcher: () => ++calls > 0,
+            });
+
+            const response = client.send("/retry_long", { method: "GET" });
+
+            await expect(response).rejects.toThrow();
+            assert.equal(calls, 1);
+        });
+
+        test("Should stop waiting for the next attempt when the request is cancelled", async function () {
+            const client = new Client("test_base_url");
+            client.maxRetries = 2;
+
+            let calls = 0;
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/retry_cancel",
+                replyCode: 503,
+                replyHeaders: { "Retry-After": "10" }, // longer than the test timeout
+                additionalMatcher: () => ++calls > 0,
+            });
+
+            const response = client.send("/retry_cancel", {
+                method: "GET",
+                requestKey: "retry_cancel",
+            });
+
+            setTimeout(() => client.cancelRequest("retry_cancel"), 20);
+
+            try {
+                await response;
+                assert.fail("expected to throw");
+            } catch (err: any) {
+                assert.isTrue(err.isAbort);
+            }
+            assert.equal(calls, 1);
+        });
+    });
+
+    describe("timeout", function () {
+        test("Should abort the request after the specified timeout", async function () {
+            const client = new Client("test_base_url");
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/timeout_slow",
+                delay: 20,
+                replyCode: 200,
+            });
+
+            try {
+                await client.send("/timeout_slow", { method: "GET", timeout: 5 });
+                assert.fail("expected to throw");
+            } catch (err: any) {
+                assert.isTrue(err.isAbort);
+                assert.equal(err.message, "The request timed out after 5ms.");
+            }
+        });
+
+        test("Should stop the retries after the specified timeout", async function () {
+            const client = new Client("test_base_url");
+            client.maxRetries = 3;
+
+            let calls = 0;
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/timeout_retry",
+                replyCode: 503,
+                replyHeaders: { "Retry-After": "10" }, // longer than the test timeout
+                additionalMatcher: () => ++calls > 0,
+            });
+
+            const start = Date.now();
+
+            try {
+                await client.send("/timeout_retry", { method: "GET", timeout: 20 });
+                assert.fail("expected to throw");
+            } catch (err: any) {
+                assert.isTrue(err.isAbort);
+                assert.equal(err.message, "The request timed out after 20ms.");
+            }
+
+            assert.equal(calls, 1);
+            assert.isBelow(Date.now() - start, 1000);
+        });
+
+        test("Should keep the cancellation error if cancelled before the timeout", async function () {
+            const client = new Client("test_base_url");
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/timeout_cancel",
+                delay: 20,
+                replyCode: 200,
+            });
+
+            const response = client.send("/timeout_cancel", {
+                method: "GET",
+                timeout: 1000,
+                requestKey: "timeout_cancel",
+            });
+
+            client.cancelRequest("timeout_cancel");
+
+            try {
+                await response;
+                assert.fail("expected to throw");
+            } catch (err: any) {
+                assert.notInclude(err.message, "timed out");
+            }
+        });
+
+        test("Should resolve the request completed within the timeout", async function () {
+            const client = new Client("test_base_url");
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/timeout_fast",
+                replyCode: 200,
+                replyBody: { ok: true },
+            });
+
+            const response = client.send("/timeout_fast", { method: "GET", timeout: 50 });
+
+            await expect(response).resolves.toEqual({ ok: true });
+        });
+    });
+
+    describe("sendRaw()", function () {
+        test("Should resolve with the raw response", async function () {
+            const client = new Client("test_base_url");
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/raw?q1=123",
+                replyCode: 200,
+                replyBody: "raw content",
+            });
+
+            const response = await client.sendRaw("/raw", { q1: 123 });
+
+            assert.equal(response.status, 200);
+            assert.equal(await (await response.blob()).text(), "raw content");
+        });
+
+        test("Should throw ClientResponseError for failed responses", async function () {
+            const client = new Client("test_base_url");
+
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/raw_error",
+                replyCode: 400,
+                replyBody: { message: "raw error" },
+            });
+
+            const response = client.sendRaw("/raw_error", {});
+
+            await expect(response).rejects.toThrow("raw error");
+        });
     });
 
     describe("cancelRequest()", function () {
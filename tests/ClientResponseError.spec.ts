@@ -73,4 +73,146 @@ describe("ClientResponseError", function () {
             assert.include(err.message, "request was autocancelled");
         });
     });
+
+    describe("retryAfter", function () {
+        test("Should parse the Retry-After header in seconds", function () {
+            const err = new ClientResponseError({
+                status: 429,
+                headers: new Headers({ "Retry-After": "5" }),
+            });
+
+            assert.equal(err.retryAfter, 5000);
+        });
+
+        test("Should parse the Retry-After header as HTTP date", function () {
+            const date = new Date(Date.now() + 60000);
+            const err = new ClientResponseError({
+                status: 503,
+                headers: { "retry-after": date.toUTCString() },
+            });
+
+            assert.isNotNull(err.retryAfter);
+            assert.isAbove(err.retryAfter!, 0);
+            assert.isAtMost(err.retryAfter!, 60000);
+        });
+
+        test("Should fallback to the RateLimit-Reset header", function () {
+            const err = new ClientResponseError({
+                status: 429,
+                headers: { "RateLimit-Reset": "2" },
+            });
+
+            assert.equal(err.retryAfter, 2000);
+        });
+
+        test("Should be null if there are no retry headers", function () {
+            const testCases = [
+                new ClientResponseError({ status: 429 }),
+                new ClientResponseError({ status: 429, headers: { "Retry-After": "abc" } }),
+                new ClientResponseError("test"),
+            ];
+            for (let i in testCases) {
+                assert.isNull(testCases[i].retryAfter, "scenario " + i);
+            }
+        });
+
+        test("Should be preserved when wrapping another ClientResponseError", function () {
+            const err0 = new ClientResponseError({
+                status: 429,
+                headers: { "Retry-After": "1" },
+            });
+            const err = new ClientResponseError(err0);
+
+            assert.equal(err.retryAfter, 1000);
+        });
+    });
+
+    describe("fieldErrors", function () {
+        test("Should return the valid field errors from the error response", function () {
+            const err = new ClientResponseError({
+                status: 400,
+                response: {
+                    data: {
+                        title: { code: "validation_required", message: "Missing value." },
+                        fields: { 0: { name: { code: "a", message: "b" } } },
+                        invalid: "test",
+                    },
+                },
+            });
+
+            assert.deepEqual(err.fieldErrors, {
+                title: { code: "validation_required", message: "Missing value." },
+            });
+        });
+
+        test("Should return empty object for missing error data", function () {
+            const err = new ClientResponseError({
+                status: 400,
+                response: { data: null },
+            });
+
+            assert.deepEqual(err.fieldErrors, {});
+        });
+    });
+
+    describe("status predicates", function () {
+        test("Should check the error response kind", function () {
+            const fieldData = { title: { code: "a", message: "b" } };
+
+            const testCases: Array<[any, boolean, boolean, boolean]> = [
+                // errData, isValidationError, isAuthError, isRateLimited
+                [{ status: 0 }, false, false, false],
+                [{ status: 400 }, false, false, false],
+                [{ status: 400, response: { data: fieldData } }, true, false, false],
+                [{ status: 422, response: { data: fieldData } }, false, false, false],
+                [{ status: 401 }, false, true, false],
+                [{ status: 403 }, false, true, false],
+                [{ status: 429 }, false, false, true],
+            ];
+            for (let i in testCases) {
+                const err = new ClientResponseError(testCases[i][0]);
+                assert.equal(err.isValidationError, testCases[i][1], "validation " + i);
+                assert.equal(err.isAuthError, testCases[i][2], "auth " + i);
+                assert.equal(err.isRateLimited, testCases[i][3], "rate limit " + i);
+            }
+        });
+    });
+
+    describe("mfaId", function () {
+        test("Should return the MFA id from the error response", function () {
+            const testCases: Array<[string, any]> = [
+                ["", {}],
+                ["", { status: 400, response: { message: "test" } }],
+                ["", { status: 401, response: { mfaId: 123 } }],
+                ["test_mfa", { status: 401, response: { mfaId: "test_mfa" } }],
+            ];
+            for (let i in testCases) {
+                const err = new ClientResponseError(testCases[i][1]);
+                assert.equal(err.mfaId, testCases[i][0], "scenario " + i);
+            }
+        });
+    });
+
+    describe("isRetryable", function () {
+        test("Should loosely check whether the request could be retried", function () {
+            const testCases: Array<[boolean, any]> = [
+                [true, { isNetworkError: true }],
+                [true, { status: 408 }],
+                [true, { status: 429 }],
+                [true, { status: 500 }],
+                [true, { status: 503 }],
+                [false, { isNetworkError: true, isAbort: true }],
+                [false, { status: 0 }],
+                [false, new TypeError("test")],
+                [false, { status: 400 }],
+                [false, { status: 401 }],
+                [false, { status: 404 }],
+                [false, { status: 501 }],
+            ];
+            for (let i in testCases) {
+                const err = new ClientResponseError(testCases[i][1]);
+                assert.equal(err.isRetryable, testCases[i][0], "scenario " + i);
+            }
+        });
+    });
 });
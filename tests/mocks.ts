@@ -11,6 +11,7 @@ export type RequestMock = {
     delay?: number;
     replyCode?: number;
     replyBody?: any;
+    replyHeaders?: { [key: string]: string };
 };
 
 export function dummyJWT(payload = {}) {
@@ -75,6 +76,14 @@ export class FetchMock {
                     url: url,
                     status: mock.replyCode,
                     statusText: "test",
+                    headers: new Headers(mock.replyHeaders || {}),
+                    blob: async () => {
+                        if (typeof mock.replyBody == "string") {
+                            return new Blob([mock.replyBody]);
+                        }
+
+                        return new Blob([JSON.stringify(mock.replyBody || {})]);
+                    },
                     json: async () => {
                         if (typeof mock.replyBody == "function") {
                            return mock.replyBody();
@@ -112,3 +121,58 @@ export class FetchMock {
         this.mocks = [];
     }
 }
+
+/**
+ * Minimal EventSource mock that allows manually triggering
+ * server events and connection errors.
+ */
+export class EventSourceMock {
+    static instances: Array<EventSourceMock> = [];
+
+    url: string;
+    closed = false;
+    onerror: ((e: any) => void) | null = null;
+
+    private listeners: { [key: string]: Array<(e: any) => void> } = {};
+
+    constructor(url: string) {
+        this.url = url;
+        EventSourceMock.instances.push(this);
+    }
+
+    /**
+     * Returns the last created EventSourceMock instance.
+     */
+    static last(): EventSourceMock {
+        return EventSourceMock.instances[EventSourceMock.instances.length - 1];
+    }
+
+    addEventListener(type: string, listener: (e: any) => void) {
+        this.listeners[type] = this.listeners[type] || [];
+        this.listeners[type].push(listener);
+    }
+
+    removeEventListener(type: string, listener: (e: any) => void) {
+        this.listeners[type] = (this.listeners[type] || []).filter((l) => l !== listener);
+    }
+
+    close() {
+        this.closed = true;
+    }
+
+    /**
+     * Dispatches a server event with the specified name.
+     */
+    emit(type: string, lastEventId = "", data = "") {
+        for (let listener of this.listeners[type] || []) {
+            listener({ type, lastEventId, data });
+        }
+    }
+
+    /**
+     * Simulates a connection error.
+     */
+    fail() {
+        this.onerror?.({ type: "error" });
+    }
+}
@@ -142,4 +142,35 @@ describe("BackupService", function () {
             );
         });
     });
+
+    describe("download()", function () {
+        test("Should resolve with the raw backup response using a new file token", async function () {
+            fetchMock.on({
+                method: "POST",
+                url: service.client.buildURL("/api/files/token"),
+                additionalMatcher: (_, config) => {
+                    return config?.headers?.["x-test"] === "123";
+                },
+                replyCode: 200,
+                replyBody: { token: "@token" },
+            });
+
+            fetchMock.on({
+                method: "GET",
+                url: service.client.buildURL("/api/backups") + "/%40test?token=%40token",
+                additionalMatcher: (_, config) => {
+                    return config?.headers?.["x-test"] === "123";
+                },
+                replyCode: 200,
+                replyBody: "backup content",
+            });
+
+            const result = await service.download("@test", {
+                headers: { "x-test": "123" },
+            });
+
+            assert.equal(result.status, 200);
+            assert.equal(await (await result.blob()).text(), "backup content");
+        });
+    });
 });
@@ -1,4 +1,4 @@
-import { describe, assert, test, beforeAll, afterAll, afterEach } from "vitest";
+import { describe, assert, expect, test, beforeAll, afterAll, afterEach } from "vitest";
 import { FetchMock } from "../mocks";
 import Client from "@/Client";
 import { FileService } from "@/services/FileService";
@@ -70,4 +70,81 @@ describe("FileService", function () {
             assert.deepEqual(result, "789");
         });
     });
+
+    describe("download()", function () {
+        test("Should download the file as Blob", async function () {
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/api/files/123/456/demo.txt?thumb=100x100&token=abc",
+                additionalMatcher: (_, config) => {
+                    return config?.headers?.["x-test"] === "456";
+                },
+                replyCode: 200,
+                replyBody: "file content",
+            });
+
+            const record = { id: "456", collectionId: "123", collectionName: "789" };
+            const result = await service.download(record, "demo.txt", {
+                thumb: "100x100",
+                token: "abc",
+                headers: { "x-test": "456" },
+            });
+
+            assert.instanceOf(result, Blob);
+            assert.equal(await result.text(), "file content");
+        });
+
+        test("Should throw ClientResponseError on failed download", async function () {
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/api/files/123/456/demo.txt",
+                replyCode: 404,
+                replyBody: { message: "missing file" },
+            });
+
+            const record = { id: "456", collectionId: "123", collectionName: "789" };
+            const result = service.download(record, "demo.txt");
+
+            await expect(result).rejects.toThrow("missing file");
+        });
+
+        test("Should send the download request through the client hooks", async function () {
+            fetchMock.on({
+                method: "GET",
+                url: "test_base_url/api/files/123/456/demo.txt",
+                additionalMatcher: (_, config) => {
+                    return config?.headers?.["x-before"] === "1";
+                },
+                replyCode: 200,
+                replyBody: "file content",
+            });
+
+            let afterSendCalls = 0;
+            client.beforeSend = function (url, options) {
+                options.headers = Object.assign({}, options.headers, { "x-before": "1" });
+                return { url, options };
+            };
+            client.afterSend = function (_: Response, data: any) {
+                afterSendCalls++;
+                return data;
+            };
+
+            try {
+                const record = { id: "456", collectionId: "123", collectionName: "789" };
+                const result = await service.download(record, "demo.txt");
+
+                assert.equal(await result.text(), "file content");
+                assert.equal(afterSendCalls, 1);
+            } finally {
+                client.beforeSend = undefined;
+                client.afterSend = undefined;
+            }
+        });
+
+        test("Should throw 404 error on missing record data", async function () {
+            const result = service.download({ id: "" }, "demo.txt");
+
+            await expect(result).rejects.toThrow("Missing required record id");
+        });
+    });
 });
@@ -37,4 +37,50 @@ describe("HealthService", function () {
             assert.deepEqual(result, { code: 200, message: "test", data: {} });
         });
     });
+
+    describe("isHealthy()", function () {
+        test("Should resolve with true on successful health check", async function () {
+            fetchMock.on({
+                method: "GET",
+                url: service.client.buildURL("/api/health") + "?q1=123",
+                replyCode: 200,
+                replyBody: { code: 200, message: "test", data: {} },
+            });
+
+            const result = await service.isHealthy({ q1: 123 });
+
+            assert.isTrue(result);
+        });
+
+        test("Should resolve with false on failed health check", async function () {
+            fetchMock.on({
+                method: "GET",
+                url: service.client.buildURL("/api/health") + "?q1=123",
+                replyCode: 500,
+                replyBody: { code: 500, message: "test", data: {} },
+            });
+
+            const result = await service.isHealthy({ q1: 123 });
+
+            assert.isFalse(result);
+        });
+
+        test("Should not auto cancel overlapping health checks", async function () {
+            fetchMock.on({
+                method: "GET",
+                url: service.client.buildURL("/api/health"),
+                delay: 5,
+                replyCode: 200,
+                replyBody: { code: 200, message: "test", data: {} },
+            });
+
+            const result = await Promise.all([
+                service.isHealthy(),
+                service.isHealthy(),
+                service.check().then(() => true),
+            ]);
+
+            assert.deepEqual(result, [true, true, true]);
+        });
+    });
 });
@@ -0,0 +1,195 @@
+import { describe, assert, test, beforeAll, afterAll, afterEach } from "vitest";
+import { FetchMock, EventSourceMock, dummyJWT } from "../mocks";
+import Client from "@/Client";
+
+function sleep(ms: number): Promise<void> {
+    return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+describe("RealtimeService", function () {
+    const fetchMock = new FetchMock();
+    const originalEventSource = global.EventSource;
+
+    beforeAll(function () {
+        fetchMock.init();
+        global.EventSource = EventSourceMock as any;
+    });
+
+    afterAll(function () {
+        fetchMock.restore();
+        global.EventSource = originalEventSource;
+    });
+
+    afterEach(function () {
+        fetchMock.clearMocks();
+        EventSourceMock.instances = [];
+    });
+
+    // creates a new client with established realtime connection
+    async function connectedClient(): Promise<Client> {
+        const client = new Client("test_base_url");
+
+        fetchMock.on({
+            method: "POST",
+            url: "test_base_url/api/realtime",
+            replyCode: 204,
+        });
+
+        const subscribePromise = client.realtime.subscribe("test", () => {});
+        EventSourceMock.last().emit("PB_CONNECT", "client1");
+        await subscribePromise;
+
+        assert.isTrue(client.realtime.isConnected);
+
+        return client;
+    }
+
+    describe("auth store change", function () {
+        test("Should resubmit the subscriptions after guest -> authenticated change", async function () {
+            const token = dummyJWT({ id: "a", collectionId: "c1", type: "auth" });
+
+            let authSubmits = 0;
+            fetchMock.on({
+                method: "POST",
+                url: "test_base_url/api/realtime",
+                replyCode: 204,
+                additionalMatcher: (_, config) => {
+                    return (
+                        config?.headers?.["Authorization"] === token && ++authSubmits > 0
+                    );
+                },
+            });
+
+            const client = await connectedClient();
+
+            client.authStore.save(token, null);
+            await sleep(10);
+
+            assert.equal(authSubmits, 1);
+            assert.equal(EventSourceMock.instances.length, 1);
+            assert.isTrue(client.realtime.isConnected);
+
+            // same auth record (eg. token refresh)
+            client.authStore.save(
+                dummyJWT({ id: "a", collectionId: "c1", type: "auth", exp: 123 }),
+                null,
+            );
+            await sleep(10);
+
+            assert.equal(authSubmits, 1);
+            assert.equal(EventSourceMock.instances.length, 1);
+        });
+
+        test("Should reconnect if the guest -> authenticated resubmit fails", async function () {
+            const token = dummyJWT({ id: "a", collectionId: "c1", type: "auth" });
+
+            fetchMock.on({
+                method: "POST",
+                url: "test_base_url/api/realtime",
+                replyCode: 400,
+                additionalMatcher: (_, config) => {
+                    return config?.headers?.["Authorization"] === token;
+                },
+            });
+
+            const client = await connectedClient();
+
+            client.authStore.save(token, null);
+            await sleep(10);
+
+            assert.equal(EventSourceMock.instances.length, 2);
+            assert.isTrue(EventSourceMock.instances[0].closed);
+        });
+
+        test("Should reconnect when the authenticated record changes", async function () {
+            const client = await connectedClient();
+
+            client.authStore.save(
+                dummyJWT({ id: "a", collectionId: "c1", type: "auth" }),
+                null,
+            );
+            await sleep(10);
+            assert.equal(EventSourceMock.instances.length, 1);
+
+            // same record id but from a different collection
+            client.authStore.save(
+                dummyJWT({ id: "a", collectionId: "c2", type: "auth" }),
+                null,
+            );
+            assert.equal(EventSourceMock.instances.length, 2);
+
+            EventSourceMock.last().emit("PB_CONNECT", "client2");
+            await sleep(10);
+            assert.isTrue(client.realtime.isConnected);
+
+            // authenticated -> guest
+            client.authStore.clear();
+            assert.equal(EventSourceMock.instances.length, 3);
+        });
+
+        test("Should not invoke onDisconnect on auth store triggered reconnects", async function () {
+            const client = await connectedClient();
+
+            const disconnects: Array<Array<string>> = [];
+            client.realtime.onDisconnect = (activeSubscriptions) => {
+                disconnects.push(activeSubscriptions);
+            };
+
+            client.authStore.save(
+                dummyJWT({ id: "a", collectionId: "c1", type: "auth" }),
+                null,
+            );
+            await sleep(10);
+
+            // switch the auth record
+            client.authStore.save(
+                dummyJWT({ id: "b", collectionId: "c1", type: "auth" }),
+                null,
+            );
+            EventSourceMock.last().emit("PB_CONNECT", "client2");
+            await sleep(10);
+
+            // authenticated -> guest
+            client.authStore.clear();
+            EventSourceMock.last().emit("PB_CONNECT", "client3");
+            await sleep(10);
+
+            assert.equal(EventSourceMock.instances.length, 3);
+            assert.isTrue(client.realtime.isConnected);
+            assert.deepEqual(disconnects, []);
+
+            // connection failure (without scheduling a background reconnect)
+            client.realtime.maxReconnectAttempts = 0;
+            EventSourceMock.last().fail();
+
+            assert.deepEqual(disconnects, [["test"]]);
+        });
+    });
+
+    describe("maxReconnectAttempts", function () {
+        test("Should not reconnect if maxReconnectAttempts is 0", async function () {
+            const client = await connectedClient();
+            client.realtime.maxReconnectAttempts = 0;
+
+            EventSourceMock.last().fail();
+            await sleep(300);
+
+            assert.equal(EventSourceMock.instances.length, 1);
+            assert.isFalse(client.realtime.isConnected);
+        });
+
+        test("Should reconnect up to maxReconnectAttempts times", async function () {
+            const client = await connectedClient();
+            client.realtime.maxReconnectAttempts = 1;
+
+            EventSourceMock.last().fail();
+            await sleep(300); // the first reconnect interval is 200ms
+            assert.equal(EventSourceMock.instances.length, 2);
+
+            EventSourceMock.last().fail();
+            await sleep(400);
+            assert.equal(EventSourceMock.instances.length, 2);
+            assert.isFalse(client.realtime.isConnected);
+        });
+    });
+});
@@ -8,7 +8,7 @@ import {
     afterEach,
 } from "vitest";
 import { crudServiceTestsSuite } from "../suites";
-import { FetchMock } from "../mocks";
+import { FetchMock, dummyJWT } from "../mocks";
 import Client from "@/Client";
 import { RecordService } from "@/services/RecordService";
 import { RecordModel } from "@/tools/dtos";
@@ -890,5 +890,110 @@ describe("RecordService", function () {
             assert.equal(impersonateClient.authStore.token, "impersonate_token");
             assert.equal(impersonateClient.authStore.record?.id, "impersonate_record_id");
         });
+
+        test("Should inherit the parent client send configuration", async function () {
+            fetchMock.on({
+                method: "POST",
+                url:
+                    service.client.buildURL(service.baseCollectionPath) +
+                    "/impersonate/%40test",
+                additionalMatcher: (_, config) => {
+                    return config?.headers?.["x-before"] === "1";
+                },
+                replyCode: 200,
+                replyBody: {
+                    token: "impersonate_token",
+                    record: { id: "impersonate_record_id" },
+                },
+            });
+
+            const beforeSend = function (url: string, options: any) {
+                options.headers = Object.assign({}, options.headers, { "x-before": "1" });
+                return { url, options };
+            };
+            const afterSend = function (_: Response, data: any) {
+                return data;
+            };
+
+            service.client.beforeSend = beforeSend;
+            service.client.afterSend = afterSend;
+            service.client.maxRetries = 2;
+            service.client.autoCancellation(false);
+
+            try {
+                const impersonateClient = await service.impersonate("@test", 0);
+
+                assert.equal(impersonateClient.lang, service.client.lang);
+                assert.equal(impersonateClient.beforeSend, beforeSend);
+                assert.equal(impersonateClient.afterSend, afterSend);
+                assert.equal(impersonateClient.maxRetries, 2);
+                assert.isFalse(impersonateClient.isAutoCancellationEnabled);
+                assert.equal(impersonateClient.authStore.token, "impersonate_token");
+            } finally {
+                service.client.beforeSend = undefined;
+                service.client.afterSend = undefined;
+                service.client.maxRetries = 0;
+                service.client.autoCancellation(true);
+            }
+        });
+
+        test("Should not inherit the superuser auto refresh hook", async function () {
+            const superuserClient = new Client("test_base_url/");
+            const superusers = superuserClient.collection("_superusers");
+
+            fetchMock.on({
+                method: "POST",
+                url:
+                    superuserClient.buildURL(superusers.baseCollectionPath) +
+                    "/auth-with-password",
+                replyCode: 200,
+                replyBody: {
+                    token: dummyJWT({
+                        id: "superuser_id",
+                        type: "auth",
+                        collectionId: "pbc_3142635823",
+                        exp: Math.floor(Date.now() / 1000) + 3600,
+                    }),
+                    record: { id: "superuser_id", collectionName: "_superusers" },
+                },
+            });
+            fetchMock.on({
+                method: "POST",
+                url:
+                    superuserClient.buildURL(superusers.baseCollectionPath) +
+                    "/impersonate/%40test",
+                replyCode: 200,
+                replyBody: {
+                    token: "impersonate_token",
+                    record: { id: "impersonate_record_id" },
+                },
+            });
+
+            const userBeforeSend = function (url: string, options: any) {
+                return { url, options };
+            };
+            superuserClient.beforeSend = userBeforeSend;
+
+            await superusers.authWithPassword("test@example.com", "1234567890", {
+                autoRefreshThreshold: 30,
+            });
+
+            // the auto refresh wrapper is registered
+            assert.notEqual(superuserClient.beforeSend, userBeforeSend);
+
+            const impersonateClient = await superusers.impersonate("@test", 0);
+            assert.equal(impersonateClient.beforeSend, userBeforeSend);
+
+            // without user defined hook
+            (superuserClient as any)._resetAutoRefresh();
+            superuserClient.beforeSend = undefined;
+            await superusers.authWithPassword("test@example.com", "1234567890", {
+                autoRefreshThreshold: 30,
+            });
+            assert.isDefined(superuserClient.beforeSend);
+
+            const impersonateClient2 = await superusers.impersonate("@test", 0);
+            assert.isUndefined(impersonateClient2.beforeSend);
+        });
     });
 });
@@ -111,6 +111,87 @@ export function crudServiceTestsSuite<M>(
             },
         });
 
+        // getFullList (consistency check restart)
+        let strictRestartCalls = 0;
+        fetchMock.on({
+            method: "GET",
+            url:
+                service.client.buildURL(service.baseCrudPath) +
+                "?page=1&perPage=2&skipTotal=0&q1=strictRestart",
+            replyCode: 200,
+            replyBody: () => {
+                return {
+                    page: 1,
+                    perPage: 2,
+                    // simulate a concurrent insert after the first fetch
+                    totalItems: strictRestartCalls++ == 0 ? 3 : 4,
+                    totalPages: 2,
+                    items: [{ id: "item1" }, { id: "item2" }],
+                };
+            },
+        });
+        fetchMock.on({
+            method: "GET",
+            url:
+                service.client.buildURL(service.baseCrudPath) +
+                "?page=2&perPage=2&skipTotal=0&q1=strictRestart",
+            replyCode: 200,
+            replyBody: {
+                page: 2,
+                perPage: 2,
+                totalItems: 4,
+                totalPages: 2,
+                items: [{ id: "item3" }, { id: "item4" }],
+            },
+        });
+        fetchMock.on({
+            method: "GET",
+            url:
+                service.client.buildURL(service.baseCrudPath) +
+                "?page=3&perPage=2&skipTotal=0&q1=strictRestart",
+            replyCode: 200,
+            replyBody: {
+                page: 3,
+                perPage: 2,
+                totalItems: 4,
+                totalPages: 2,
+                items: [],
+            },
+        });
+
+        // getFullList (consistency check failure)
+        let strictFailureCalls = 0;
+        fetchMock.on({
+            method: "GET",
+            url:
+                service.client.buildURL(service.baseCrudPath) +
+                "?page=1&perPage=2&skipTotal=0&q1=strictFailure",
+            replyCode: 200,
+            replyBody: () => {
+                return {
+                    page: 1,
+                    perPage: 2,
+                    totalItems: 10 + strictFailureCalls++,
+                    totalPages: 5,
+                    items: [{ id: "item1" }, { id: "item2" }],
+                };
+            },
+        });
+        fetchMock.on({
+            method: "GET",
+            url:
+                service.client.buildURL(service.baseCrudPath) +
+                "?page=2&perPage=2&skipTotal=0&q1=strictFailure",
+            replyCode: 200,
+            replyBody: {
+                page: 2,
+                perPage: 2,
+                totalItems: 100,
+                totalPages: 50,
+                items: [{ id: "item3" }, { id: "item4" }],
+            },
+        });
+
         // getList
         fetchMock.on({
             method: "GET",
@@ -259,6 +340,97 @@ export function crudServiceTestsSuite<M>(
 
                 assert.deepEqual(result, expected);
             });
+            test("checkConsistency (restart on total items change)", async function () {
+                const result = await service.getFullList({
+                    batch: 2,
+                    q1: "strictRestart",
+                    checkConsistency: true,
+                });
+                const expected = [
+                    service.decode({ id: "item1" }),
+                    service.decode({ id: "item2" }),
+                    service.decode({ id: "item3" }),
+                    service.decode({ id: "item4" }),
+                ];
+
+                assert.deepEqual(result, expected);
+            });
+            test("checkConsistency (legacy batch argument)", async function () {
+                const result = await service.getFullList(2, {
+                    q1: "strictRestart",
+                    checkConsistency: true,
+                });
+
+                assert.equal(result.length, 4);
+            });
+            test("checkConsistency (ignore skipTotal)", async function () {
+                const result = await service.getFullList({
+                    batch: 2,
+                    q1: "strictRestart",
+                    skipTotal: true,
+                    checkConsistency: true,
+                });
+
+                assert.equal(result.length, 4);
+            });
+            test("checkConsistency (error after too many restarts)", async function () {
+                const result = service.getFullList({
+                    batch: 2,
+                    q1: "strictFailure",
+                    checkConsistency: true,
+                });
+
+                await expect(result).rejects.toThrow("changed while fetching");
+            });
+        });
+
+        describe("iterate()", function () {
+            test("items.length == batchSize (aka. empty request stop check)", async function () {
+                const result: Array<M> = [];
+                for await (const item of service.iterate({
+                    batch: 1,
+                    q1: "emptyRequest",
+                    headers: { "x-test": "789" },
+                })) {
+                    result.push(item);
+                }
+                const expected = [
+                    service.decode({ id: "item1" }),
+                    service.decode({ id: "item2" }),
+                ];
+
+                assert.deepEqual(result, expected);
+            });
+            test("items.length < batchSize (aka. no empty request stop check)", async function () {
+                const result: Array<M> = [];
+                for await (const item of service.iterate({
+                    batch: 2,
+                    q1: "noEmptyRequest",
+                    headers: { "x-test": "789" },
+                })) {
+                    result.push(item);
+                }
+                const expected = [
+                    service.decode({ id: "item1" }),
+                    service.decode({ id: "item2" }),
+                    service.decode({ id: "item3" }),
+                ];
+
+                assert.deepEqual(result, expected);
+            });
+            test("checkConsistency (not sent as query param)", async function () {
+                const result: Array<M> = [];
+                for await (const item of service.iterate({
+                    batch: 2,
+                    q1: "noEmptyRequest",
+                    headers: { "x-test": "789" },
+                    checkConsistency: true,
+                })) {
+                    result.push(item);
+                }
+
+                assert.equal(result.length, 3);
+            });
         });
 
         describe("getList()", function () {
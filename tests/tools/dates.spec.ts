@@ -0,0 +1,38 @@
+import { describe, assert, test } from "vitest";
+import { formatDate, parseDate } from "@/tools/dates";
+
+describe("dates", function () {
+    describe("formatDate()", function () {
+        test("Should format the date in the PocketBase datetime format", function () {
+            assert.equal(
+                formatDate(new Date("2025-01-02T03:04:05.678Z")),
+                "2025-01-02 03:04:05.678Z",
+            );
+        });
+    });
+
+    describe("parseDate()", function () {
+        test("Should parse PocketBase and ISO datetime strings", function () {
+            const testCases = [
+                "2025-01-02 03:04:05.678Z",
+                "2025-01-02T03:04:05.678Z",
+                " 2025-01-02 03:04:05.678Z ",
+            ];
+            for (let i in testCases) {
+                const date = parseDate(testCases[i]);
+                assert.equal(
+                    date?.toISOString(),
+                    "2025-01-02T03:04:05.678Z",
+                    "scenario " + i,
+                );
+            }
+        });
+
+        test("Should return null for empty or invalid datetime strings", function () {
+            const testCases: Array<any> = ["", null, undefined, "abc", 123];
+            for (let i in testCases) {
+                assert.isNull(parseDate(testCases[i]), "scenario " + i);
+            }
+        });
+    });
+});